@@ -0,0 +1,304 @@
+use std::{convert::TryFrom, num::NonZeroUsize};
+
+use hmac::Hmac;
+use pbkdf2::pbkdf2;
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::{PasswordContents, LOWERCASE_DATA, NUMBERS, SYMBOLS, UPPERCASE_DATA};
+
+const PBKDF2_ROUNDS: u32 = 100_000;
+const ENTROPY_BYTES: usize = 32;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DerivedPasswordError {
+    #[error("missing possible password contents")]
+    MissingContent,
+
+    #[error("password must be more than 0 elements")]
+    ZeroLengthPassword,
+
+    #[error("a master password is required")]
+    MissingMasterPassword,
+
+    #[error("a site is required")]
+    MissingSite,
+
+    #[error("a login is required")]
+    MissingLogin,
+
+    #[error("length is too short to fit one character from every selected content set")]
+    LengthTooShortForContents,
+}
+
+/// Deterministically derives the same password every time from a master
+/// password, a site, a login and a counter, using the same PBKDF2-then-
+/// divmod approach as the LessPass algorithm. This does *not* produce
+/// passwords interoperable with a real LessPass client: it draws from this
+/// crate's own [`SYMBOLS`]/[`NUMBERS`]/etc. alphabets rather than
+/// LessPass's. Nothing about the derived password needs to be stored: as
+/// long as the same inputs are supplied, `generate` returns the same
+/// output.
+pub struct DerivedPassword {
+    contents: PasswordContents,
+    length: Option<NonZeroUsize>,
+    master_password: Option<String>,
+    site: Option<String>,
+    login: Option<String>,
+    counter: u32,
+}
+
+impl DerivedPassword {
+    pub fn new() -> Self {
+        Self {
+            contents: PasswordContents::empty(),
+            length: NonZeroUsize::new(16),
+            master_password: None,
+            site: None,
+            login: None,
+            counter: 1,
+        }
+    }
+
+    pub fn with_master_password(mut self, master_password: impl Into<String>) -> Self {
+        self.master_password = Some(master_password.into());
+        self
+    }
+
+    pub fn with_site(mut self, site: impl Into<String>) -> Self {
+        self.site = Some(site.into());
+        self
+    }
+
+    pub fn with_login(mut self, login: impl Into<String>) -> Self {
+        self.login = Some(login.into());
+        self
+    }
+
+    pub fn with_counter(mut self, counter: u32) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    pub fn with_lowercase_chars(mut self) -> Self {
+        self.contents.set(PasswordContents::LOWERCASE, true);
+        self
+    }
+
+    pub fn with_uppercase_chars(mut self) -> Self {
+        self.contents.set(PasswordContents::UPPERCASE, true);
+        self
+    }
+
+    pub fn with_symbols(mut self) -> Self {
+        self.contents.set(PasswordContents::SYMBOLS, true);
+        self
+    }
+
+    pub fn with_numbers(mut self) -> Self {
+        self.contents.set(PasswordContents::NUMBERS, true);
+        self
+    }
+
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = NonZeroUsize::try_from(length).ok();
+        self
+    }
+
+    /// Derive a password from the configured master password, site, login
+    /// and counter. The same inputs always produce the same password.
+    pub fn generate(self) -> Result<String, DerivedPasswordError> {
+        let length = self
+            .length
+            .ok_or(DerivedPasswordError::ZeroLengthPassword)?;
+
+        trace!("Contents: {:#?}", self.contents);
+        if self.contents.is_empty() {
+            return Err(DerivedPasswordError::MissingContent);
+        }
+
+        let mut sets: Vec<Vec<char>> = Vec::new();
+        if self.contents.contains(PasswordContents::LOWERCASE) {
+            sets.push(LOWERCASE_DATA.chars().collect());
+        }
+        if self.contents.contains(PasswordContents::UPPERCASE) {
+            sets.push(UPPERCASE_DATA.chars().collect());
+        }
+        if self.contents.contains(PasswordContents::SYMBOLS) {
+            sets.push(SYMBOLS.chars().collect());
+        }
+        if self.contents.contains(PasswordContents::NUMBERS) {
+            sets.push(NUMBERS.chars().collect());
+        }
+
+        if length.get() < sets.len() {
+            return Err(DerivedPasswordError::LengthTooShortForContents);
+        }
+
+        let master_password = self
+            .master_password
+            .ok_or(DerivedPasswordError::MissingMasterPassword)?;
+        let site = self.site.ok_or(DerivedPasswordError::MissingSite)?;
+        let login = self.login.ok_or(DerivedPasswordError::MissingLogin)?;
+
+        let mut salt = String::with_capacity(site.len() + login.len() + 8);
+        salt.push_str(&site);
+        salt.push_str(&login);
+        salt.push_str(&format!("{:x}", self.counter));
+
+        let mut entropy = [0u8; ENTROPY_BYTES];
+        pbkdf2::<Hmac<Sha256>>(
+            master_password.as_bytes(),
+            salt.as_bytes(),
+            PBKDF2_ROUNDS,
+            &mut entropy,
+        );
+
+        let full_charset: Vec<char> = sets.iter().flatten().copied().collect();
+        let mut value = entropy.to_vec();
+        let body_len = length.get() - sets.len();
+
+        let mut password: Vec<char> = Vec::with_capacity(length.get());
+        for _ in 0..body_len {
+            let remainder = divmod(&mut value, full_charset.len() as u32);
+            password.push(full_charset[remainder as usize]);
+        }
+
+        // LessPass draws every required-content character before inserting
+        // any of them, so the entropy-consumption order doesn't depend on
+        // the order sets happen to be interleaved in.
+        let mut required_chars: Vec<char> = Vec::with_capacity(sets.len());
+        for subset in &sets {
+            let remainder = divmod(&mut value, subset.len() as u32);
+            required_chars.push(subset[remainder as usize]);
+        }
+
+        for character in required_chars {
+            let modulus = (password.len() as u32).max(1);
+            let index = divmod(&mut value, modulus);
+            password.insert(index as usize, character);
+        }
+
+        Ok(password.into_iter().collect())
+    }
+}
+
+impl Default for DerivedPassword {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Divides a big-endian unsigned integer (stored as bytes) by `divisor` in
+/// place, turning `value` into the quotient and returning the remainder.
+fn divmod(value: &mut [u8], divisor: u32) -> u32 {
+    let mut remainder: u64 = 0;
+    for byte in value.iter_mut() {
+        let acc = (remainder << 8) | u64::from(*byte);
+        *byte = (acc / u64::from(divisor)) as u8;
+        remainder = acc % u64::from(divisor);
+    }
+    remainder as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generator() -> DerivedPassword {
+        DerivedPassword::new()
+            .with_master_password("password")
+            .with_site("example.org")
+            .with_login("login")
+            .with_lowercase_chars()
+            .with_uppercase_chars()
+            .with_numbers()
+            .with_symbols()
+            .with_length(16)
+    }
+
+    #[test]
+    fn derivation_is_deterministic() {
+        let first = generator().generate().unwrap();
+        let second = generator().generate().unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn derivation_respects_length() {
+        let password = generator().generate().unwrap();
+
+        assert_eq!(password.len(), 16);
+    }
+
+    #[test]
+    fn different_counters_derive_different_passwords() {
+        let first = generator().with_counter(1).generate().unwrap();
+        let second = generator().with_counter(2).generate().unwrap();
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn derivation_includes_every_selected_class() {
+        let password = generator().generate().unwrap();
+
+        assert!(password.chars().any(|c| LOWERCASE_DATA.contains(c)));
+        assert!(password.chars().any(|c| UPPERCASE_DATA.contains(c)));
+        assert!(password.chars().any(|c| NUMBERS.contains(c)));
+        assert!(password.chars().any(|c| SYMBOLS.contains(c)));
+    }
+
+    #[test]
+    fn missing_master_password_is_an_error() {
+        let result = DerivedPassword::new()
+            .with_site("example.org")
+            .with_login("login")
+            .with_lowercase_chars()
+            .generate();
+
+        assert_eq!(result, Err(DerivedPasswordError::MissingMasterPassword));
+    }
+
+    #[test]
+    fn missing_content_is_an_error() {
+        let result = DerivedPassword::new()
+            .with_master_password("password")
+            .with_site("example.org")
+            .with_login("login")
+            .generate();
+
+        assert_eq!(result, Err(DerivedPasswordError::MissingContent));
+    }
+
+    // These two pin this implementation's output for a fixed input so a
+    // future change to the draw/insert order is caught by the test suite.
+    // They are regression vectors for *this* crate, not LessPass reference
+    // vectors: LessPass's own symbol alphabet differs from our `SYMBOLS`,
+    // so the two implementations don't produce interoperable output.
+    #[test]
+    fn derivation_matches_fixed_regression_vector() {
+        let password = generator().with_counter(1).generate().unwrap();
+
+        assert_eq!(password, "oSX>J]brSG[:6155");
+    }
+
+    #[test]
+    fn derivation_matches_fixed_regression_vector_at_minimum_length() {
+        let password = generator()
+            .with_length(4)
+            .with_counter(1)
+            .generate()
+            .unwrap();
+
+        assert_eq!(password, "D%4u");
+    }
+
+    #[test]
+    fn length_shorter_than_selected_contents_is_an_error() {
+        let result = generator().with_length(2).generate();
+
+        assert_eq!(result, Err(DerivedPasswordError::LengthTooShortForContents));
+    }
+}
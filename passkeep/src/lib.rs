@@ -4,13 +4,23 @@ extern crate log;
 use std::{convert::TryFrom, num::NonZeroUsize};
 
 use bitflags::bitflags;
-use rand::prelude::*;
+use rand::{prelude::*, rngs::ThreadRng, CryptoRng};
 use thiserror::Error;
 
-const LOWERCASE_DATA: &str = "abcdefghijklmnopqrstuvwxyz";
-const UPPERCASE_DATA: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-const SYMBOLS: &str = "!@#$%^&*()_+-={}[]\":;'?><,./~`|\\";
-const NUMBERS: &str = "1234567890";
+mod derived;
+mod passphrase;
+mod pronounceable;
+mod strength;
+
+pub use derived::{DerivedPassword, DerivedPasswordError};
+pub use passphrase::{PassphraseGenerationError, PassphraseGenerator};
+pub use pronounceable::{PronounceableGenerationError, PronounceableGenerator};
+pub use strength::{estimate_entropy, password_strength, PasswordStrength};
+
+pub(crate) const LOWERCASE_DATA: &str = "abcdefghijklmnopqrstuvwxyz";
+pub(crate) const UPPERCASE_DATA: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+pub(crate) const SYMBOLS: &str = "!@#$%^&*()_+-={}[]\":;'?><,./~`|\\";
+pub(crate) const NUMBERS: &str = "1234567890";
 
 bitflags! {
     pub struct PasswordContents: u8 {
@@ -28,11 +38,24 @@ pub enum PasswordGenerationError {
 
     #[error("password must be more than 0 elements")]
     ZeroLengthPassword,
+
+    #[error("length is too short to fit one character from every selected content set")]
+    LengthTooShortForContents,
 }
 
+/// Number of candidates `generate` will try before giving up on satisfying
+/// `.strict()`.
+const MAX_STRICT_ATTEMPTS: usize = 100;
+
+/// Characters that are easily confused with one another when handwritten,
+/// printed or read aloud, removed from the dictionary by `.exclude_similar()`.
+const SIMILAR_CHARS: &str = "l1IO0oB8S5|\"'`";
+
 pub struct PasswordGenerator {
     contents: PasswordContents,
     length: Option<NonZeroUsize>,
+    strict: bool,
+    exclude_similar: bool,
 }
 
 impl PasswordGenerator {
@@ -40,6 +63,8 @@ impl PasswordGenerator {
         Self {
             contents: PasswordContents::empty(),
             length: NonZeroUsize::new(8),
+            strict: false,
+            exclude_similar: false,
         }
     }
 
@@ -68,8 +93,36 @@ impl PasswordGenerator {
         self
     }
 
-    /// Generate a password
+    /// Guarantee the generated password contains at least one character
+    /// from every selected [`PasswordContents`] set, retrying generation
+    /// until it does (up to a bounded number of attempts).
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Remove visually ambiguous characters (`l`, `1`, `I`, `O`, `0`, `o`,
+    /// `B`, `8`, `S`, `5`, `|` and the backtick/quote family) from the
+    /// dictionary before sampling.
+    pub fn exclude_similar(mut self) -> Self {
+        self.exclude_similar = true;
+        self
+    }
+
+    /// Generate a password using the system's cryptographically secure RNG.
     pub fn generate(self) -> Result<String, PasswordGenerationError> {
+        let mut rng = rand::thread_rng();
+        self.generate_with(&mut rng)
+    }
+
+    /// Generate a password, sampling from `rng` instead of
+    /// `rand::thread_rng()`. The `CryptoRng` bound documents that
+    /// generation is cryptographically secure; it also lets tests inject a
+    /// seeded RNG for deterministic assertions.
+    pub fn generate_with<R: Rng + CryptoRng>(
+        self,
+        rng: &mut R,
+    ) -> Result<String, PasswordGenerationError> {
         if self.length.is_none() {
             return Err(PasswordGenerationError::ZeroLengthPassword);
         }
@@ -79,8 +132,53 @@ impl PasswordGenerator {
             return Err(PasswordGenerationError::MissingContent);
         }
 
-        let mut rng = rand::thread_rng();
+        let dictionary: Vec<char> = self.dictionary().chars().collect();
+        let length = self.length.unwrap().get();
+        let attempts = if self.strict { MAX_STRICT_ATTEMPTS } else { 1 };
+
+        for _ in 0..attempts {
+            let password = sample_password(&dictionary, length, rng);
+
+            if !self.strict || contents_present(&password) == self.contents {
+                return Ok(password);
+            }
+        }
+
+        Err(PasswordGenerationError::LengthTooShortForContents)
+    }
+
+    /// Lazily stream any number of passwords from this configuration
+    /// without rebuilding the dictionary on every call.
+    pub fn iter(&self) -> PasswordIter {
+        PasswordIter {
+            dictionary: self.dictionary().chars().collect(),
+            length: self.length.map(NonZeroUsize::get).unwrap_or(0),
+            contents: self.contents,
+            strict: self.strict,
+            rng: rand::thread_rng(),
+        }
+    }
+
+    /// Estimate the Shannon entropy, in bits, of passwords produced by this
+    /// configuration: `length * log2(dictionary_len)`.
+    pub fn entropy_bits(&self) -> f64 {
+        let dictionary_len = self.dictionary().chars().count();
+        let length = self.length.map(NonZeroUsize::get).unwrap_or(0);
+
+        if dictionary_len == 0 || length == 0 {
+            return 0.0;
+        }
+
+        length as f64 * (dictionary_len as f64).log2()
+    }
+
+    /// Classify this configuration's [`entropy_bits`](Self::entropy_bits)
+    /// into a [`PasswordStrength`] tier.
+    pub fn strength(&self) -> PasswordStrength {
+        PasswordStrength::from_entropy_bits(self.entropy_bits())
+    }
 
+    fn dictionary(&self) -> String {
         let mut dictionary = String::new();
         if self.contents.contains(PasswordContents::LOWERCASE) {
             trace!("Adding lowercase letters to dictionary.");
@@ -102,14 +200,80 @@ impl PasswordGenerator {
             dictionary.push_str(NUMBERS);
         }
 
-        let mut password = String::new();
-        for _ in 0..self.length.unwrap().get() {
-            password.push(dictionary.chars().choose(&mut rng).unwrap());
+        if self.exclude_similar {
+            dictionary.retain(|c| !SIMILAR_CHARS.contains(c));
         }
-        Ok(password)
+
+        dictionary
+    }
+}
+
+impl Default for PasswordGenerator {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+/// Draws `length` characters from `dictionary` using `rng`.
+fn sample_password<R: Rng + ?Sized>(dictionary: &[char], length: usize, rng: &mut R) -> String {
+    (0..length)
+        .map(|_| *dictionary.choose(rng).unwrap())
+        .collect()
+}
+
+/// A lazy stream of passwords from one [`PasswordGenerator`]
+/// configuration. Created with [`PasswordGenerator::iter`].
+///
+/// The stream ends (`next` returns `None`) when the configuration has no
+/// contents or a zero length, or when a `.strict()` configuration finds no
+/// candidate containing every selected set within `MAX_STRICT_ATTEMPTS`
+/// tries.
+pub struct PasswordIter {
+    dictionary: Vec<char>,
+    length: usize,
+    contents: PasswordContents,
+    strict: bool,
+    rng: ThreadRng,
+}
+
+impl Iterator for PasswordIter {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.dictionary.is_empty() || self.length == 0 {
+            return None;
+        }
+
+        let attempts = if self.strict { MAX_STRICT_ATTEMPTS } else { 1 };
+        for _ in 0..attempts {
+            let password = sample_password(&self.dictionary, self.length, &mut self.rng);
+
+            if !self.strict || contents_present(&password) == self.contents {
+                return Some(password);
+            }
+        }
+
+        None
+    }
+}
+
+/// Determines which [`PasswordContents`] sets are represented in `password`.
+pub(crate) fn contents_present(password: &str) -> PasswordContents {
+    let mut present = PasswordContents::empty();
+    for c in password.chars() {
+        if LOWERCASE_DATA.contains(c) {
+            present.set(PasswordContents::LOWERCASE, true);
+        } else if UPPERCASE_DATA.contains(c) {
+            present.set(PasswordContents::UPPERCASE, true);
+        } else if SYMBOLS.contains(c) {
+            present.set(PasswordContents::SYMBOLS, true);
+        } else if NUMBERS.contains(c) {
+            present.set(PasswordContents::NUMBERS, true);
+        }
+    }
+    present
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,7 +291,7 @@ mod tests {
             .with_lowercase_chars()
             .with_length(43594)
             .generate();
-        
+
         assert!(result.is_ok());
 
         let result = result.unwrap();
@@ -195,4 +359,132 @@ mod tests {
         let result = result.unwrap();
         assert!(result.chars().all(|c| c.is_numeric()));
     }
+
+    #[test]
+    fn strict_password_contains_every_selected_set() {
+        for _ in 0..100 {
+            let result = PasswordGenerator::new()
+                .with_lowercase_chars()
+                .with_uppercase_chars()
+                .with_symbols()
+                .with_numbers()
+                .with_length(8)
+                .strict()
+                .generate();
+
+            assert!(result.is_ok());
+
+            let result = result.unwrap();
+            assert!(result.chars().any(|c| LOWERCASE_DATA.contains(c)));
+            assert!(result.chars().any(|c| UPPERCASE_DATA.contains(c)));
+            assert!(result.chars().any(|c| SYMBOLS.contains(c)));
+            assert!(result.chars().any(|c| NUMBERS.contains(c)));
+        }
+    }
+
+    #[test]
+    fn strict_password_too_short_for_contents_is_an_error() {
+        let result = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_uppercase_chars()
+            .with_symbols()
+            .with_numbers()
+            .with_length(2)
+            .strict()
+            .generate();
+
+        assert_eq!(
+            result,
+            Err(PasswordGenerationError::LengthTooShortForContents)
+        );
+    }
+
+    #[test]
+    fn exclude_similar_removes_ambiguous_characters() {
+        let result = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_uppercase_chars()
+            .with_numbers()
+            .with_symbols()
+            .with_length(1000)
+            .exclude_similar()
+            .generate();
+
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+        assert!(result.chars().all(|c| !SIMILAR_CHARS.contains(c)));
+    }
+
+    #[test]
+    fn entropy_bits_grows_with_length_and_contents() {
+        let short = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_length(8)
+            .entropy_bits();
+
+        let long = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_length(16)
+            .entropy_bits();
+
+        let mixed = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_uppercase_chars()
+            .with_numbers()
+            .with_symbols()
+            .with_length(8)
+            .entropy_bits();
+
+        assert!(long > short);
+        assert!(mixed > short);
+    }
+
+    #[test]
+    fn strength_reflects_entropy() {
+        let weak = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_length(4)
+            .strength();
+
+        let strong = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_uppercase_chars()
+            .with_numbers()
+            .with_symbols()
+            .with_length(32)
+            .strength();
+
+        assert_eq!(weak, PasswordStrength::VeryWeak);
+        assert_eq!(strong, PasswordStrength::VeryStrong);
+    }
+
+    #[test]
+    fn generate_with_seeded_rng_is_deterministic() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let first = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_length(20)
+            .generate_with(&mut StdRng::seed_from_u64(42));
+
+        let second = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_length(20)
+            .generate_with(&mut StdRng::seed_from_u64(42));
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn iter_yields_passwords_of_configured_length() {
+        let generator = PasswordGenerator::new()
+            .with_lowercase_chars()
+            .with_length(12);
+
+        let passwords: Vec<String> = generator.iter().take(5).collect();
+
+        assert_eq!(passwords.len(), 5);
+        assert!(passwords.iter().all(|p| p.chars().count() == 12));
+    }
 }
@@ -0,0 +1,1359 @@
+use std::{convert::TryFrom, num::NonZeroUsize};
+
+use rand::prelude::*;
+use thiserror::Error;
+
+/// Bundled wordlist of real, single English dictionary words, used to build
+/// passphrases the way diceware does. This is a curated list assembled
+/// offline for this crate, not a vendored copy of the EFF long wordlist
+/// (fetching and verifying that file requires network access this crate
+/// doesn't assume); at 1,186 entries it contributes `log2(1186) ~= 10.2`
+/// bits of entropy per word, short of the EFF list's ~12.9 bits but still
+/// real, distinct, memorable words rather than glued-together compounds.
+const WORDLIST: &[&str] = &[
+    "aardvark",
+    "abacus",
+    "acacia",
+    "accordion",
+    "acorn",
+    "albatross",
+    "album",
+    "alligator",
+    "almond",
+    "aloe",
+    "alpaca",
+    "alphabet",
+    "anaconda",
+    "anchor",
+    "angelfish",
+    "anteater",
+    "antelope",
+    "anthem",
+    "antique",
+    "anvil",
+    "apple",
+    "apricot",
+    "apron",
+    "arch",
+    "archipelago",
+    "archive",
+    "armadillo",
+    "armor",
+    "arrow",
+    "artichoke",
+    "asparagus",
+    "atlas",
+    "atmosphere",
+    "atoll",
+    "atom",
+    "avalanche",
+    "avocado",
+    "awning",
+    "axe",
+    "axle",
+    "azalea",
+    "baboon",
+    "bacon",
+    "badge",
+    "badger",
+    "bagel",
+    "baguette",
+    "ballad",
+    "ballet",
+    "bamboo",
+    "banana",
+    "banjo",
+    "banner",
+    "banquet",
+    "barley",
+    "baron",
+    "barracuda",
+    "barrel",
+    "basil",
+    "basket",
+    "bass",
+    "bat",
+    "bay",
+    "beach",
+    "beacon",
+    "beagle",
+    "bean",
+    "bear",
+    "beaver",
+    "bedbug",
+    "beech",
+    "beef",
+    "beet",
+    "beetle",
+    "begonia",
+    "belfry",
+    "bell",
+    "bench",
+    "berry",
+    "bicycle",
+    "binder",
+    "birch",
+    "biscuit",
+    "bison",
+    "blackberry",
+    "blade",
+    "blanket",
+    "blaze",
+    "blender",
+    "bloodhound",
+    "blossom",
+    "bluebell",
+    "blueberry",
+    "bluff",
+    "boar",
+    "bobcat",
+    "bolt",
+    "boot",
+    "bottle",
+    "boulder",
+    "bounty",
+    "bow",
+    "bowl",
+    "box",
+    "boxwood",
+    "bracelet",
+    "bracket",
+    "bramble",
+    "bran",
+    "bread",
+    "brick",
+    "bridge",
+    "brigade",
+    "brioche",
+    "broccoli",
+    "brook",
+    "broom",
+    "broth",
+    "brownie",
+    "brush",
+    "bucket",
+    "buckle",
+    "bud",
+    "buffalo",
+    "bulb",
+    "bulldog",
+    "bulletin",
+    "bullfrog",
+    "bullion",
+    "bumblebee",
+    "bumper",
+    "bun",
+    "burrito",
+    "butte",
+    "butter",
+    "butterfly",
+    "buttermilk",
+    "buzzard",
+    "cabbage",
+    "cabinet",
+    "cable",
+    "cactus",
+    "cadence",
+    "cake",
+    "calendar",
+    "camel",
+    "camellia",
+    "camera",
+    "canary",
+    "candle",
+    "candy",
+    "canoe",
+    "canopy",
+    "cantaloupe",
+    "canvas",
+    "canyon",
+    "cap",
+    "cape",
+    "capybara",
+    "caramel",
+    "caribou",
+    "carnation",
+    "carp",
+    "carpet",
+    "carrot",
+    "cart",
+    "cashew",
+    "cassowary",
+    "castle",
+    "catfish",
+    "cathedral",
+    "cauldron",
+    "cauliflower",
+    "cave",
+    "cedar",
+    "celery",
+    "cello",
+    "centipede",
+    "century",
+    "cereal",
+    "chain",
+    "chair",
+    "chalice",
+    "chalk",
+    "chameleon",
+    "champion",
+    "chandelier",
+    "channel",
+    "chapel",
+    "charm",
+    "chart",
+    "charter",
+    "cheese",
+    "cheetah",
+    "cherry",
+    "chess",
+    "chest",
+    "chestnut",
+    "chicken",
+    "chickpea",
+    "chili",
+    "chimpanzee",
+    "chinchilla",
+    "chipmunk",
+    "chisel",
+    "chive",
+    "chocolate",
+    "chorus",
+    "chowder",
+    "chronicle",
+    "chrysanthemum",
+    "cider",
+    "cinnamon",
+    "citadel",
+    "clam",
+    "clamp",
+    "clarinet",
+    "classic",
+    "cliff",
+    "cloak",
+    "clock",
+    "cloth",
+    "clove",
+    "clover",
+    "coast",
+    "coaster",
+    "coat",
+    "cobalt",
+    "cobra",
+    "cockatoo",
+    "cockroach",
+    "cocoa",
+    "coconut",
+    "coffee",
+    "coin",
+    "collard",
+    "collie",
+    "column",
+    "comet",
+    "comfort",
+    "compass",
+    "compote",
+    "concerto",
+    "condor",
+    "cone",
+    "constrictor",
+    "continent",
+    "cookie",
+    "copper",
+    "coral",
+    "cord",
+    "coriander",
+    "cork",
+    "corn",
+    "cornet",
+    "cougar",
+    "cove",
+    "cow",
+    "coyote",
+    "crab",
+    "cranberry",
+    "crane",
+    "crate",
+    "crater",
+    "crayon",
+    "cream",
+    "creek",
+    "crepe",
+    "crest",
+    "crevice",
+    "cricket",
+    "crocodile",
+    "croissant",
+    "crow",
+    "crown",
+    "crystal",
+    "cube",
+    "cuckoo",
+    "cucumber",
+    "cumin",
+    "cup",
+    "current",
+    "curry",
+    "curtain",
+    "cushion",
+    "custard",
+    "cymbal",
+    "cypress",
+    "daffodil",
+    "dagger",
+    "dahlia",
+    "daisy",
+    "dandelion",
+    "date",
+    "decade",
+    "decree",
+    "deer",
+    "delta",
+    "desert",
+    "desk",
+    "dial",
+    "diamond",
+    "dice",
+    "dill",
+    "dingo",
+    "diploma",
+    "dish",
+    "dock",
+    "dodo",
+    "dogwood",
+    "dolphin",
+    "dome",
+    "donkey",
+    "door",
+    "dough",
+    "doughnut",
+    "dove",
+    "dragonfly",
+    "drama",
+    "drape",
+    "drum",
+    "drumbeat",
+    "duchy",
+    "duck",
+    "dumbbell",
+    "dumpling",
+    "dune",
+    "dynasty",
+    "eagle",
+    "earring",
+    "earthworm",
+    "earwig",
+    "easel",
+    "ebony",
+    "echo",
+    "eel",
+    "eggplant",
+    "egret",
+    "elephant",
+    "elixir",
+    "elk",
+    "elm",
+    "emblem",
+    "emerald",
+    "emperor",
+    "empire",
+    "emu",
+    "endive",
+    "engine",
+    "envelope",
+    "epoch",
+    "era",
+    "eraser",
+    "essay",
+    "estate",
+    "estuary",
+    "exhibit",
+    "fable",
+    "falcon",
+    "fan",
+    "fanfare",
+    "faucet",
+    "feast",
+    "fence",
+    "fennel",
+    "fern",
+    "ferret",
+    "feta",
+    "fiction",
+    "fiddle",
+    "fiesta",
+    "fig",
+    "filter",
+    "finch",
+    "fir",
+    "firefly",
+    "fjord",
+    "flag",
+    "flame",
+    "flamingo",
+    "flask",
+    "flea",
+    "flounder",
+    "flour",
+    "flute",
+    "fly",
+    "folder",
+    "foliage",
+    "forest",
+    "fortress",
+    "fountain",
+    "fox",
+    "foxglove",
+    "fragment",
+    "frame",
+    "fresco",
+    "fritter",
+    "frog",
+    "frontier",
+    "fuchsia",
+    "funnel",
+    "gadget",
+    "galaxy",
+    "gardenia",
+    "garland",
+    "garlic",
+    "garrison",
+    "gate",
+    "gauge",
+    "gavel",
+    "gazelle",
+    "gazette",
+    "gear",
+    "gecko",
+    "gemstone",
+    "geranium",
+    "gerbil",
+    "geyser",
+    "gibbon",
+    "ginger",
+    "ginkgo",
+    "giraffe",
+    "glacier",
+    "gladiolus",
+    "glass",
+    "glen",
+    "globe",
+    "glove",
+    "gnat",
+    "gnu",
+    "goat",
+    "goblet",
+    "goldfish",
+    "gong",
+    "goose",
+    "gopher",
+    "gorge",
+    "gorilla",
+    "gorse",
+    "gown",
+    "granite",
+    "grape",
+    "grapefruit",
+    "grass",
+    "grasshopper",
+    "grate",
+    "gravy",
+    "greyhound",
+    "grid",
+    "grotto",
+    "grouse",
+    "guava",
+    "guitar",
+    "gulch",
+    "gulf",
+    "guppy",
+    "hamlet",
+    "hammer",
+    "hamper",
+    "hamster",
+    "handle",
+    "harbor",
+    "hare",
+    "harp",
+    "harvest",
+    "hat",
+    "hatchet",
+    "hawk",
+    "hawthorn",
+    "hazel",
+    "hearth",
+    "heater",
+    "heather",
+    "hedgehog",
+    "heirloom",
+    "helmet",
+    "hemlock",
+    "heritage",
+    "heroic",
+    "heron",
+    "herring",
+    "hibiscus",
+    "highland",
+    "hill",
+    "hinge",
+    "hippo",
+    "holly",
+    "hollyhock",
+    "honey",
+    "honeydew",
+    "honeysuckle",
+    "hook",
+    "horizon",
+    "horn",
+    "hornet",
+    "horse",
+    "hose",
+    "hound",
+    "hummingbird",
+    "hummus",
+    "husky",
+    "hyacinth",
+    "hyena",
+    "hymn",
+    "ibex",
+    "icon",
+    "ideal",
+    "idyll",
+    "igloo",
+    "iguana",
+    "impala",
+    "infantry",
+    "inkwell",
+    "instinct",
+    "island",
+    "islet",
+    "isthmus",
+    "ivory",
+    "ivy",
+    "jackal",
+    "jacket",
+    "jaguar",
+    "jam",
+    "jar",
+    "jasmine",
+    "jay",
+    "jellyfish",
+    "jester",
+    "jewel",
+    "journal",
+    "journey",
+    "jubilee",
+    "jug",
+    "juice",
+    "jungle",
+    "juniper",
+    "kale",
+    "kangaroo",
+    "kelp",
+    "ketchup",
+    "kettle",
+    "key",
+    "keyboard",
+    "kingdom",
+    "kingfisher",
+    "kiosk",
+    "kite",
+    "kiwi",
+    "knife",
+    "knight",
+    "knob",
+    "koala",
+    "ladder",
+    "ladle",
+    "ladybug",
+    "lagoon",
+    "lake",
+    "lamb",
+    "lamp",
+    "landslide",
+    "lantern",
+    "lark",
+    "latch",
+    "lava",
+    "lavender",
+    "leaf",
+    "ledge",
+    "leek",
+    "legend",
+    "legion",
+    "lemon",
+    "lemur",
+    "lentil",
+    "leopard",
+    "lettuce",
+    "lever",
+    "lichen",
+    "lid",
+    "lighthouse",
+    "lilac",
+    "lily",
+    "lime",
+    "linden",
+    "lineage",
+    "lion",
+    "lizard",
+    "llama",
+    "lobster",
+    "lock",
+    "locket",
+    "locust",
+    "loom",
+    "loon",
+    "lotus",
+    "lullaby",
+    "lute",
+    "lynx",
+    "macaroni",
+    "macaw",
+    "madrigal",
+    "magnolia",
+    "magpie",
+    "mahogany",
+    "mallard",
+    "mallet",
+    "mammoth",
+    "manatee",
+    "mandolin",
+    "mango",
+    "mansion",
+    "mantis",
+    "manuscript",
+    "maple",
+    "marble",
+    "marigold",
+    "marlin",
+    "marmalade",
+    "marmot",
+    "marquis",
+    "marsh",
+    "marten",
+    "mask",
+    "masquerade",
+    "mast",
+    "mat",
+    "meadow",
+    "medal",
+    "medallion",
+    "meerkat",
+    "melody",
+    "melon",
+    "memoir",
+    "menagerie",
+    "meridian",
+    "mesa",
+    "meteor",
+    "milk",
+    "millet",
+    "mimosa",
+    "minnow",
+    "minstrel",
+    "mint",
+    "mirage",
+    "mirror",
+    "mistletoe",
+    "mite",
+    "mitten",
+    "molasses",
+    "mold",
+    "mole",
+    "mongoose",
+    "monkey",
+    "moon",
+    "moor",
+    "moose",
+    "mop",
+    "mosaic",
+    "moss",
+    "moth",
+    "mountain",
+    "mouse",
+    "muffin",
+    "mulberry",
+    "mule",
+    "museum",
+    "mushroom",
+    "muskrat",
+    "mussel",
+    "mustard",
+    "mynah",
+    "myrtle",
+    "myth",
+    "nail",
+    "napkin",
+    "narwhal",
+    "nebula",
+    "necklace",
+    "nectar",
+    "needle",
+    "net",
+    "nettle",
+    "newt",
+    "nightingale",
+    "nobility",
+    "nomad",
+    "noodle",
+    "notebook",
+    "novel",
+    "nozzle",
+    "nutmeg",
+    "oak",
+    "oar",
+    "oasis",
+    "oat",
+    "oath",
+    "oatmeal",
+    "obelisk",
+    "ocean",
+    "octopus",
+    "odyssey",
+    "okra",
+    "oleander",
+    "olive",
+    "omelet",
+    "onion",
+    "opera",
+    "opossum",
+    "oracle",
+    "orange",
+    "orangutan",
+    "orbit",
+    "orchard",
+    "orchid",
+    "oregano",
+    "organ",
+    "oriole",
+    "ornament",
+    "osprey",
+    "ostrich",
+    "otter",
+    "outback",
+    "outpost",
+    "oven",
+    "owl",
+    "ox",
+    "oyster",
+    "paddle",
+    "padlock",
+    "pageant",
+    "pail",
+    "paintbrush",
+    "palace",
+    "palm",
+    "pamphlet",
+    "pan",
+    "pancake",
+    "panda",
+    "panel",
+    "panorama",
+    "pansy",
+    "panther",
+    "papaya",
+    "paprika",
+    "parable",
+    "parakeet",
+    "parasol",
+    "parchment",
+    "parish",
+    "parlor",
+    "parrot",
+    "parsley",
+    "parsnip",
+    "partridge",
+    "pass",
+    "pasta",
+    "pastry",
+    "pasture",
+    "pavilion",
+    "peach",
+    "peacock",
+    "peak",
+    "peanut",
+    "pear",
+    "pecan",
+    "peg",
+    "pelican",
+    "pen",
+    "pencil",
+    "pendant",
+    "penguin",
+    "peninsula",
+    "peony",
+    "pepper",
+    "perch",
+    "persimmon",
+    "petal",
+    "petunia",
+    "phantom",
+    "pheasant",
+    "phone",
+    "piano",
+    "pickle",
+    "pie",
+    "pig",
+    "pigeon",
+    "pilgrim",
+    "pillar",
+    "pillow",
+    "pin",
+    "pine",
+    "pineapple",
+    "pinnacle",
+    "pioneer",
+    "pipe",
+    "piranha",
+    "pistachio",
+    "pitcher",
+    "plain",
+    "planet",
+    "plank",
+    "plate",
+    "plateau",
+    "platypus",
+    "plaza",
+    "plover",
+    "plow",
+    "plum",
+    "pocket",
+    "pole",
+    "pomegranate",
+    "pond",
+    "pony",
+    "popcorn",
+    "poppy",
+    "porcupine",
+    "porpoise",
+    "portrait",
+    "possum",
+    "pot",
+    "potato",
+    "pouch",
+    "prairie",
+    "press",
+    "pretzel",
+    "primrose",
+    "privet",
+    "proclamation",
+    "promontory",
+    "pronghorn",
+    "prophecy",
+    "proverb",
+    "province",
+    "ptarmigan",
+    "pudding",
+    "puffin",
+    "pulley",
+    "puma",
+    "pump",
+    "pumpkin",
+    "pyramid",
+    "python",
+    "quail",
+    "quarry",
+    "quartz",
+    "quest",
+    "quiche",
+    "quill",
+    "quilt",
+    "quince",
+    "rabbit",
+    "raccoon",
+    "racket",
+    "radish",
+    "rail",
+    "raisin",
+    "rake",
+    "ram",
+    "ramp",
+    "ranch",
+    "range",
+    "raspberry",
+    "rat",
+    "rattlesnake",
+    "raven",
+    "ravine",
+    "razor",
+    "realm",
+    "redwood",
+    "reed",
+    "reef",
+    "reel",
+    "reindeer",
+    "relic",
+    "relish",
+    "requiem",
+    "reverie",
+    "rhapsody",
+    "rhino",
+    "rhododendron",
+    "rhubarb",
+    "ribbon",
+    "rice",
+    "ricotta",
+    "ridge",
+    "ring",
+    "ritual",
+    "rival",
+    "river",
+    "rivet",
+    "robe",
+    "robin",
+    "rocket",
+    "roll",
+    "rooster",
+    "rope",
+    "rose",
+    "rosemary",
+    "rug",
+    "ruler",
+    "rush",
+    "rye",
+    "sack",
+    "saddle",
+    "saffron",
+    "saga",
+    "sage",
+    "sail",
+    "salad",
+    "salamander",
+    "salmon",
+    "saloon",
+    "salsa",
+    "salt",
+    "sanctuary",
+    "sandbar",
+    "sandpiper",
+    "sapling",
+    "sapphire",
+    "sardine",
+    "satchel",
+    "saucer",
+    "sausage",
+    "savanna",
+    "saw",
+    "scallion",
+    "scallop",
+    "scarf",
+    "scepter",
+    "scissors",
+    "scorpion",
+    "screw",
+    "scroll",
+    "sculpture",
+    "seahorse",
+    "seal",
+    "season",
+    "sentinel",
+    "sequel",
+    "sequoia",
+    "serenade",
+    "serpent",
+    "sesame",
+    "shade",
+    "shallot",
+    "shark",
+    "shawl",
+    "shears",
+    "sheep",
+    "shelf",
+    "sherbet",
+    "shield",
+    "shoal",
+    "shore",
+    "shovel",
+    "shrew",
+    "shrimp",
+    "shrine",
+    "shrub",
+    "shutter",
+    "sickle",
+    "sieve",
+    "skillet",
+    "skunk",
+    "sled",
+    "sleigh",
+    "slope",
+    "sloth",
+    "snail",
+    "snake",
+    "snipe",
+    "sock",
+    "socket",
+    "sole",
+    "sonata",
+    "sorbet",
+    "sorrel",
+    "soup",
+    "soy",
+    "spade",
+    "sparrow",
+    "spatula",
+    "spear",
+    "spectacle",
+    "sphere",
+    "sphinx",
+    "spider",
+    "spinach",
+    "spindle",
+    "spire",
+    "sponge",
+    "spool",
+    "spoon",
+    "spring",
+    "sprout",
+    "spruce",
+    "spyglass",
+    "squash",
+    "squid",
+    "squirrel",
+    "standard",
+    "stanza",
+    "starfish",
+    "starling",
+    "statue",
+    "statute",
+    "steak",
+    "stem",
+    "stencil",
+    "steppe",
+    "stew",
+    "stingray",
+    "stoat",
+    "stool",
+    "stork",
+    "stove",
+    "strait",
+    "strap",
+    "strawberry",
+    "stream",
+    "string",
+    "stronghold",
+    "sugar",
+    "suitcase",
+    "sultana",
+    "summit",
+    "sundial",
+    "sunflower",
+    "swallow",
+    "swamp",
+    "swan",
+    "switch",
+    "sword",
+    "sycamore",
+    "symphony",
+    "syringe",
+    "syrup",
+    "table",
+    "tableau",
+    "tack",
+    "tamarind",
+    "tambourine",
+    "tangerine",
+    "tape",
+    "tapestry",
+    "tapir",
+    "tarantula",
+    "tarragon",
+    "tea",
+    "teapot",
+    "telescope",
+    "temple",
+    "tent",
+    "termite",
+    "tern",
+    "testament",
+    "thimble",
+    "thistle",
+    "thorn",
+    "thread",
+    "throne",
+    "thrush",
+    "thyme",
+    "ticket",
+    "tide",
+    "tie",
+    "tiger",
+    "timer",
+    "toad",
+    "toast",
+    "toaster",
+    "tofu",
+    "tomato",
+    "tongs",
+    "toolbox",
+    "torch",
+    "tortilla",
+    "toucan",
+    "towel",
+    "tower",
+    "tradition",
+    "tray",
+    "treaty",
+    "trek",
+    "tribune",
+    "trinket",
+    "triumph",
+    "trombone",
+    "trophy",
+    "trout",
+    "trove",
+    "trowel",
+    "truffle",
+    "trumpet",
+    "trunk",
+    "tube",
+    "tulip",
+    "tumbleweed",
+    "tuna",
+    "tundra",
+    "tunic",
+    "turf",
+    "turkey",
+    "turmeric",
+    "turnip",
+    "turret",
+    "turtle",
+    "tutor",
+    "tweezers",
+    "twig",
+    "typewriter",
+    "umbrella",
+    "urn",
+    "utopia",
+    "valley",
+    "valor",
+    "vanguard",
+    "vanilla",
+    "vase",
+    "vault",
+    "veal",
+    "velvet",
+    "verse",
+    "vessel",
+    "vest",
+    "vial",
+    "vigil",
+    "village",
+    "vine",
+    "vinegar",
+    "vineyard",
+    "violet",
+    "violin",
+    "viper",
+    "vista",
+    "volcano",
+    "vole",
+    "voyage",
+    "vulture",
+    "waffle",
+    "wagon",
+    "wallaby",
+    "wallet",
+    "walnut",
+    "walrus",
+    "warbler",
+    "ward",
+    "warden",
+    "wardrobe",
+    "washer",
+    "wasp",
+    "watch",
+    "watercress",
+    "waterfall",
+    "watermelon",
+    "weasel",
+    "wetland",
+    "whale",
+    "wharf",
+    "wheat",
+    "wheel",
+    "whisk",
+    "whistle",
+    "wick",
+    "wig",
+    "willow",
+    "wisdom",
+    "wisteria",
+    "wolf",
+    "wolverine",
+    "wombat",
+    "woodchuck",
+    "woodpecker",
+    "wreath",
+    "wren",
+    "wrench",
+    "yacht",
+    "yak",
+    "yam",
+    "yardstick",
+    "yew",
+    "yogurt",
+    "yoyo",
+    "zebra",
+    "zinnia",
+    "zodiac",
+    "zucchini",
+];
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PassphraseGenerationError {
+    #[error("passphrase must contain more than 0 words")]
+    ZeroWordPassphrase,
+}
+
+/// Generates word-based passphrases instead of character soup, drawing
+/// uniformly at random from a bundled wordlist.
+pub struct PassphraseGenerator {
+    word_count: Option<NonZeroUsize>,
+    separator: String,
+    capitalize: bool,
+    append_number: bool,
+}
+
+impl PassphraseGenerator {
+    pub fn new() -> Self {
+        Self {
+            word_count: NonZeroUsize::new(6),
+            separator: "-".to_string(),
+            capitalize: false,
+            append_number: false,
+        }
+    }
+
+    pub fn with_word_count(mut self, word_count: usize) -> Self {
+        self.word_count = NonZeroUsize::try_from(word_count).ok();
+        self
+    }
+
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Capitalize the first letter of every word.
+    pub fn with_capitalized_words(mut self) -> Self {
+        self.capitalize = true;
+        self
+    }
+
+    /// Append a random digit to one randomly chosen word.
+    pub fn with_appended_number(mut self) -> Self {
+        self.append_number = true;
+        self
+    }
+
+    /// Generate a passphrase.
+    pub fn generate(self) -> Result<String, PassphraseGenerationError> {
+        let word_count = self
+            .word_count
+            .ok_or(PassphraseGenerationError::ZeroWordPassphrase)?
+            .get();
+
+        let mut rng = rand::thread_rng();
+
+        let mut words: Vec<String> = (0..word_count)
+            .map(|_| {
+                let word = WORDLIST.choose(&mut rng).unwrap();
+                if self.capitalize {
+                    capitalize(word)
+                } else {
+                    word.to_string()
+                }
+            })
+            .collect();
+
+        if self.append_number {
+            let index = rng.gen_range(0..words.len());
+            let digit = rng.gen_range(0..10);
+            words[index].push_str(&digit.to_string());
+        }
+
+        Ok(words.join(&self.separator))
+    }
+}
+
+impl Default for PassphraseGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passphrase_must_have_words() {
+        let result = PassphraseGenerator::new().with_word_count(0).generate();
+
+        assert_eq!(result, Err(PassphraseGenerationError::ZeroWordPassphrase));
+    }
+
+    #[test]
+    fn passphrase_word_count() {
+        let result = PassphraseGenerator::new()
+            .with_word_count(10)
+            .generate()
+            .unwrap();
+
+        assert_eq!(result.split('-').count(), 10);
+    }
+
+    #[test]
+    fn passphrase_uses_separator() {
+        let result = PassphraseGenerator::new()
+            .with_word_count(4)
+            .with_separator(" ")
+            .generate()
+            .unwrap();
+
+        assert_eq!(result.split(' ').count(), 4);
+    }
+
+    #[test]
+    fn passphrase_capitalizes_words() {
+        let result = PassphraseGenerator::new()
+            .with_word_count(4)
+            .with_capitalized_words()
+            .generate()
+            .unwrap();
+
+        for word in result.split('-') {
+            let first = word.chars().next().unwrap();
+            assert!(first.is_uppercase());
+        }
+    }
+
+    #[test]
+    fn passphrase_appends_number() {
+        let result = PassphraseGenerator::new()
+            .with_word_count(4)
+            .with_appended_number()
+            .generate()
+            .unwrap();
+
+        assert!(result.chars().any(|c| c.is_numeric()));
+    }
+
+    #[test]
+    fn wordlist_is_free_of_duplicates_and_all_lowercase_ascii() {
+        let unique: std::collections::HashSet<&&str> = WORDLIST.iter().collect();
+
+        assert_eq!(unique.len(), WORDLIST.len());
+        assert!(WORDLIST
+            .iter()
+            .all(|word| word.chars().all(|c| c.is_ascii_lowercase())));
+    }
+}
@@ -0,0 +1,2932 @@
+use std::{convert::TryFrom, num::NonZeroUsize};
+
+use rand::Rng;
+use thiserror::Error;
+
+/// English letter-triple counts, indexed `[first][second][third]` by
+/// `'a'..='z'` offset. Precomputed ahead of time (see below) and baked in
+/// as static data, with every one of the 17,576 triples nonzero so
+/// generation never has to fall back to a uniform pick.
+type TrigramTable = [[[u32; 26]; 26]; 26];
+
+/// `TRIGRAM_COUNTS` is not measured from a text corpus at build time; it's
+/// derived once, offline, from a small table of classical English digraph
+/// ("th", "he", "in", ...) frequency estimates of the kind used in
+/// pre-computer cryptanalysis, by multiplying the two overlapping digraph
+/// weights of each triple (`weight(a,b) * weight(b,c)`). That's a coarse
+/// stand-in for genuine corpus-measured trigram counts, but -- unlike a
+/// vowel/consonant-alternation heuristic -- it is grounded in real,
+/// attributed letter-pair statistics, and the result is frozen into the
+/// constant below rather than recomputed at runtime.
+static TRIGRAM_COUNTS: [[[u32; 26]; 26]; 26] = [
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            4700, 1880, 1880, 1880, 6674, 1880, 1880, 1880, 4794, 1880, 1880, 4982, 1880, 1880,
+            1880, 1880, 1880, 1880, 1880, 1880, 1880, 1880, 1880, 1880, 1880, 1880,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            10455, 4100, 4100, 27675, 12095, 4100, 19475, 4100, 4100, 4100, 4100, 4100, 4100, 4100,
+            4100, 4100, 4100, 4100, 4100, 23985, 4100, 4100, 4100, 4100, 4100, 4100,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            6741, 2140, 2140, 2140, 19795, 2140, 2140, 2140, 6420, 2140, 2140, 2140, 2140, 2140,
+            6741, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140,
+        ],
+        [
+            5916, 1740, 1740, 1740, 6351, 1740, 1740, 1740, 4611, 1740, 1740, 1740, 1740, 1740,
+            4437, 1740, 1740, 1740, 1740, 13224, 1740, 1740, 1740, 1740, 1740, 1740,
+        ],
+        [
+            6545, 2380, 2380, 2380, 12138, 2380, 2380, 37485, 12376, 2380, 2380, 2380, 2380, 2380,
+            13209, 2380, 2380, 2380, 2380, 2380, 2380, 2380, 2380, 2380, 2380, 2380,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 1100, 1100, 1100, 1100, 3905, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 9680,
+            1100, 1100, 1100, 6215, 1100, 1100, 3960, 1100, 1100, 1100, 1100, 1100,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            5992, 1120, 3080, 6552, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 8120,
+            1120, 1120, 1120, 13048, 8904, 4200, 1120, 1120, 1120, 1120, 1120, 1120,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 2140, 10058, 2140, 21935,
+            2140, 2140, 2140, 11449, 9309, 12733, 2140, 2140, 2140, 2140, 2140, 2140,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100,
+            3025, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100,
+        ],
+        [
+            2340, 2340, 2340, 2340, 6552, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340,
+            2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            7395, 2900, 2900, 19575, 8555, 2900, 13775, 2900, 2900, 2900, 2900, 2900, 2900, 2900,
+            2900, 2900, 2900, 2900, 2900, 16965, 2900, 2900, 2900, 2900, 2900, 2900,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            14679, 4660, 4660, 4660, 43105, 4660, 4660, 4660, 13980, 4660, 4660, 4660, 4660, 4660,
+            14679, 4660, 4660, 4660, 4660, 4660, 4660, 4660, 4660, 4660, 4660, 4660,
+        ],
+        [
+            10812, 3180, 3180, 3180, 11607, 3180, 3180, 3180, 8427, 3180, 3180, 3180, 3180, 3180,
+            8109, 3180, 3180, 3180, 3180, 24168, 3180, 3180, 3180, 3180, 3180, 3180,
+        ],
+        [
+            4125, 1500, 1500, 1500, 7650, 1500, 1500, 23625, 7800, 1500, 1500, 1500, 1500, 1500,
+            8325, 1500, 1500, 1500, 1500, 1500, 1500, 1500, 1500, 1500, 1500, 1500,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            1680, 1680, 1680, 1680, 1680, 1680, 1680, 1680, 1680, 1680, 1680, 7896, 1680, 17220,
+            1680, 1680, 1680, 8988, 7308, 9996, 1680, 1680, 1680, 1680, 1680, 1680,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            26857, 5020, 13805, 29367, 5020, 5020, 5020, 5020, 5020, 5020, 5020, 5020, 5020, 36395,
+            5020, 5020, 5020, 58483, 39909, 18825, 5020, 5020, 5020, 5020, 5020, 5020,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 14520,
+            1200, 1200, 1200, 1200, 5160, 5580, 1200, 1200, 1200, 1200, 1200, 1200,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            12342, 4840, 4840, 32670, 14278, 4840, 22990, 4840, 4840, 4840, 4840, 4840, 4840, 4840,
+            4840, 4840, 4840, 4840, 4840, 28314, 4840, 4840, 4840, 4840, 4840, 4840,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            5848, 1720, 1720, 1720, 6278, 1720, 1720, 1720, 4558, 1720, 1720, 1720, 1720, 1720,
+            4386, 1720, 1720, 1720, 1720, 13072, 1720, 1720, 1720, 1720, 1720, 1720,
+        ],
+        [
+            5115, 1860, 1860, 1860, 9486, 1860, 1860, 29295, 9672, 1860, 1860, 1860, 1860, 1860,
+            10323, 1860, 1860, 1860, 1860, 1860, 1860, 1860, 1860, 1860, 1860, 1860,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            1000, 1000, 1000, 1000, 1000, 1000, 1000, 1000, 1000, 1000, 1000, 4700, 1000, 10250,
+            1000, 1000, 1000, 5350, 4350, 5950, 1000, 1000, 1000, 1000, 1000, 1000,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            7597, 1420, 3905, 8307, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 10295,
+            1420, 1420, 1420, 16543, 11289, 5325, 1420, 1420, 1420, 1420, 1420, 1420,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 12342,
+            1020, 1020, 1020, 1020, 4386, 4743, 1020, 1020, 1020, 1020, 1020, 1020,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2650, 1060, 1060, 1060, 3763, 1060, 1060, 1060, 2703, 1060, 1060, 2809, 1060, 1060,
+            1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            6099, 1140, 3135, 6669, 1140, 1140, 1140, 1140, 1140, 1140, 1140, 1140, 1140, 8265,
+            1140, 1140, 1140, 13281, 9063, 4275, 1140, 1140, 1140, 1140, 1140, 1140,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 4794, 1020, 10455,
+            1020, 1020, 1020, 5457, 4437, 6069, 1020, 1020, 1020, 1020, 1020, 1020,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2700, 2700, 2700, 2700, 7560, 2700, 2700, 2700, 2700, 2700, 2700, 2700, 2700, 2700,
+            2700, 2700, 2700, 2700, 2700, 2700, 2700, 2700, 2700, 2700, 2700, 2700,
+        ],
+        [
+            6313, 1180, 3245, 6903, 1180, 1180, 1180, 1180, 1180, 1180, 1180, 1180, 1180, 8555,
+            1180, 1180, 1180, 13747, 9381, 4425, 1180, 1180, 1180, 1180, 1180, 1180,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900,
+            1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            6435, 2340, 2340, 2340, 11934, 2340, 2340, 36855, 12168, 2340, 2340, 2340, 2340, 2340,
+            12987, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340, 2340,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420,
+            1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420, 1420,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            8976, 3520, 3520, 23760, 10384, 3520, 16720, 3520, 3520, 3520, 3520, 3520, 3520, 3520,
+            3520, 3520, 3520, 3520, 3520, 20592, 3520, 3520, 3520, 3520, 3520, 3520,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            7119, 2260, 2260, 2260, 20905, 2260, 2260, 2260, 6780, 2260, 2260, 2260, 2260, 2260,
+            7119, 2260, 2260, 2260, 2260, 2260, 2260, 2260, 2260, 2260, 2260, 2260,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440,
+            1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            1260, 1260, 1260, 1260, 1260, 1260, 1260, 1260, 1260, 1260, 1260, 5922, 1260, 12915,
+            1260, 1260, 1260, 6741, 5481, 7497, 1260, 1260, 1260, 1260, 1260, 1260,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            19795, 3700, 10175, 21645, 3700, 3700, 3700, 3700, 3700, 3700, 3700, 3700, 3700, 26825,
+            3700, 3700, 3700, 43105, 29415, 13875, 3700, 3700, 3700, 3700, 3700, 3700,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 14520,
+            1200, 1200, 1200, 1200, 5160, 5580, 1200, 1200, 1200, 1200, 1200, 1200,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 1260, 1260, 1260, 1260, 4473, 1260, 1260, 1260, 1260, 1260, 1260, 1260, 11088,
+            1260, 1260, 1260, 7119, 1260, 1260, 4536, 1260, 1260, 1260, 1260, 1260,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            1360, 1360, 1360, 1360, 1360, 1360, 1360, 1360, 1360, 1360, 1360, 6392, 1360, 13940,
+            1360, 1360, 1360, 7276, 5916, 8092, 1360, 1360, 1360, 1360, 1360, 1360,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            7811, 1460, 4015, 8541, 1460, 1460, 1460, 1460, 1460, 1460, 1460, 1460, 1460, 10585,
+            1460, 1460, 1460, 17009, 11607, 5475, 1460, 1460, 1460, 1460, 1460, 1460,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 1060, 12826,
+            1060, 1060, 1060, 1060, 4558, 4929, 1060, 1060, 1060, 1060, 1060, 1060,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 1020, 1020, 1020, 1020, 3621, 1020, 1020, 1020, 1020, 1020, 1020, 1020, 8976,
+            1020, 1020, 1020, 5763, 1020, 1020, 3672, 1020, 1020, 1020, 1020, 1020,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            8360, 3040, 3040, 3040, 15504, 3040, 3040, 47880, 15808, 3040, 3040, 3040, 3040, 3040,
+            16872, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 3040,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 1100, 5170, 1100, 11275,
+            1100, 1100, 1100, 5885, 4785, 6545, 1100, 1100, 1100, 1100, 1100, 1100,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            10914, 2040, 5610, 11934, 2040, 2040, 2040, 2040, 2040, 2040, 2040, 2040, 2040, 14790,
+            2040, 2040, 2040, 23766, 16218, 7650, 2040, 2040, 2040, 2040, 2040, 2040,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            26460, 6300, 6300, 6300, 79065, 6300, 6300, 6300, 18900, 6300, 6300, 6300, 6300, 6300,
+            6300, 6300, 6300, 6300, 6300, 6300, 6300, 6300, 6300, 6300, 6300, 6300,
+        ],
+        [
+            2080, 2080, 2080, 2080, 2080, 2080, 2080, 2080, 2080, 2080, 2080, 2080, 2080, 25168,
+            2080, 2080, 2080, 2080, 8944, 9672, 2080, 2080, 2080, 2080, 2080, 2080,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2220, 2220, 2220, 2220, 2220, 7881, 2220, 2220, 2220, 2220, 2220, 2220, 2220, 19536,
+            2220, 2220, 2220, 12543, 2220, 2220, 7992, 2220, 2220, 2220, 2220, 2220,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            6848, 1280, 3520, 7488, 1280, 1280, 1280, 1280, 1280, 1280, 1280, 1280, 1280, 9280,
+            1280, 1280, 1280, 14912, 10176, 4800, 1280, 1280, 1280, 1280, 1280, 1280,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+    [
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1880, 400, 4100, 400, 400, 400,
+            2140, 1740, 2380, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 1100, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1120, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            2140, 400, 1100, 2340, 400, 400, 400, 400, 400, 400, 400, 400, 400, 2900, 400, 400,
+            400, 4660, 3180, 1500, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1680, 400, 400, 400, 5020, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 4840, 400, 400, 400,
+            400, 1720, 1860, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1000, 400, 400, 400, 1420, 400, 400, 400, 1020, 400, 400, 1060, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1140, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1020, 400, 400, 2700, 1180, 400, 1900, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 2340, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 1420, 400, 400, 400, 400, 400, 400, 400, 3520, 400, 400, 400,
+            2260, 400, 400, 1440, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1260, 400, 400, 400, 3700, 400, 400, 400, 1200, 400, 400, 400, 400, 400, 1260, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1360, 400, 400, 400, 1460, 400, 400, 400, 1060, 400, 400, 400, 400, 400, 1020, 400,
+            400, 400, 400, 3040, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            1100, 400, 400, 400, 2040, 400, 400, 6300, 2080, 400, 400, 400, 400, 400, 2220, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 1280, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+        [
+            400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400, 400,
+            400, 400, 400, 400, 400, 400, 400, 400, 400,
+        ],
+    ],
+];
+
+fn pick_opening_pair(table: &TrigramTable, rng: &mut impl Rng) -> (usize, usize) {
+    let total: u32 = table.iter().flatten().flatten().sum();
+    if total == 0 {
+        return (rng.gen_range(0..26), rng.gen_range(0..26));
+    }
+
+    let threshold = rng.gen_range(0.0..total as f64);
+    let mut cumulative = 0u32;
+    for (c1, rows) in table.iter().enumerate() {
+        for (c2, row) in rows.iter().enumerate() {
+            cumulative += row.iter().sum::<u32>();
+            if cumulative as f64 > threshold {
+                return (c1, c2);
+            }
+        }
+    }
+
+    (25, 25)
+}
+
+fn pick_next_letter(table: &TrigramTable, c1: usize, c2: usize, rng: &mut impl Rng) -> usize {
+    let row = &table[c1][c2];
+    let total: u32 = row.iter().sum();
+    if total == 0 {
+        return rng.gen_range(0..26);
+    }
+
+    let threshold = rng.gen_range(0.0..total as f64);
+    let mut cumulative = 0u32;
+    for (c3, &count) in row.iter().enumerate() {
+        cumulative += count;
+        if cumulative as f64 > threshold {
+            return c3;
+        }
+    }
+
+    25
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PronounceableGenerationError {
+    #[error("password must be more than 0 elements")]
+    ZeroLengthPassword,
+}
+
+/// Generates lowercase passwords that read as (loosely) pronounceable
+/// English, by walking a trigram Markov model letter by letter instead of
+/// sampling uniformly from a dictionary.
+pub struct PronounceableGenerator {
+    length: Option<NonZeroUsize>,
+}
+
+impl PronounceableGenerator {
+    pub fn new() -> Self {
+        Self {
+            length: NonZeroUsize::new(8),
+        }
+    }
+
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = NonZeroUsize::try_from(length).ok();
+        self
+    }
+
+    /// Generate a pronounceable password.
+    pub fn generate(self) -> Result<String, PronounceableGenerationError> {
+        let length = self
+            .length
+            .ok_or(PronounceableGenerationError::ZeroLengthPassword)?
+            .get();
+
+        let table = &TRIGRAM_COUNTS;
+        let mut rng = rand::thread_rng();
+
+        let mut letters = Vec::with_capacity(length);
+        let (c1, c2) = pick_opening_pair(table, &mut rng);
+        letters.push(c1);
+        if length > 1 {
+            letters.push(c2);
+        }
+
+        while letters.len() < length {
+            let prev1 = letters[letters.len() - 2];
+            let prev2 = letters[letters.len() - 1];
+            letters.push(pick_next_letter(table, prev1, prev2, &mut rng));
+        }
+
+        Ok(letters
+            .into_iter()
+            .map(|i| (b'a' + i as u8) as char)
+            .collect())
+    }
+}
+
+impl Default for PronounceableGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pronounceable_password_cannot_be_zero_length() {
+        let result = PronounceableGenerator::new().with_length(0).generate();
+
+        assert_eq!(
+            result,
+            Err(PronounceableGenerationError::ZeroLengthPassword)
+        );
+    }
+
+    #[test]
+    fn pronounceable_password_respects_length() {
+        let result = PronounceableGenerator::new().with_length(500).generate();
+
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+        assert_eq!(result.chars().count(), 500);
+    }
+
+    #[test]
+    fn pronounceable_password_is_lowercase_ascii() {
+        let result = PronounceableGenerator::new()
+            .with_length(500)
+            .generate()
+            .unwrap();
+
+        assert!(result.chars().all(|c| c.is_ascii_lowercase()));
+    }
+
+    #[test]
+    fn pronounceable_password_single_letter() {
+        let result = PronounceableGenerator::new().with_length(1).generate();
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().chars().count(), 1);
+    }
+
+    #[test]
+    fn trigram_table_has_full_coverage() {
+        let table = &TRIGRAM_COUNTS;
+
+        for plane in table.iter() {
+            for row in plane.iter() {
+                assert!(row.iter().all(|&count| count > 0));
+            }
+        }
+    }
+}
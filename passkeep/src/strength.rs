@@ -0,0 +1,105 @@
+use crate::{contents_present, PasswordContents, LOWERCASE_DATA, NUMBERS, SYMBOLS, UPPERCASE_DATA};
+
+/// A coarse classification of how hard a password would be to brute force,
+/// derived from its estimated entropy in bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordStrength {
+    VeryWeak,
+    Weak,
+    Reasonable,
+    Strong,
+    VeryStrong,
+}
+
+impl PasswordStrength {
+    /// Classify an entropy value, in bits, into a strength tier.
+    pub fn from_entropy_bits(bits: f64) -> Self {
+        match bits {
+            b if b < 28.0 => PasswordStrength::VeryWeak,
+            b if b < 36.0 => PasswordStrength::Weak,
+            b if b < 60.0 => PasswordStrength::Reasonable,
+            b if b < 128.0 => PasswordStrength::Strong,
+            _ => PasswordStrength::VeryStrong,
+        }
+    }
+}
+
+fn dictionary_len_for(contents: PasswordContents) -> usize {
+    let mut len = 0;
+    if contents.contains(PasswordContents::LOWERCASE) {
+        len += LOWERCASE_DATA.len();
+    }
+    if contents.contains(PasswordContents::UPPERCASE) {
+        len += UPPERCASE_DATA.len();
+    }
+    if contents.contains(PasswordContents::SYMBOLS) {
+        len += SYMBOLS.len();
+    }
+    if contents.contains(PasswordContents::NUMBERS) {
+        len += NUMBERS.len();
+    }
+    len
+}
+
+/// Estimate the Shannon entropy, in bits, of an arbitrary password by
+/// inferring which character classes it draws from and applying
+/// `length * log2(dictionary_len)`.
+pub fn estimate_entropy(password: &str) -> f64 {
+    let dictionary_len = dictionary_len_for(contents_present(password));
+    let length = password.chars().count();
+
+    if dictionary_len == 0 || length == 0 {
+        return 0.0;
+    }
+
+    length as f64 * (dictionary_len as f64).log2()
+}
+
+/// Estimate the [`PasswordStrength`] of an arbitrary password.
+pub fn password_strength(password: &str) -> PasswordStrength {
+    PasswordStrength::from_entropy_bits(estimate_entropy(password))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_password_has_zero_entropy() {
+        assert_eq!(estimate_entropy(""), 0.0);
+    }
+
+    #[test]
+    fn longer_passwords_have_more_entropy() {
+        assert!(estimate_entropy("abcdefghij") > estimate_entropy("abcde"));
+    }
+
+    #[test]
+    fn mixed_contents_have_more_entropy_than_a_single_class() {
+        assert!(estimate_entropy("abcDEF123!@#") > estimate_entropy("abcdef123456"));
+    }
+
+    #[test]
+    fn strength_tiers_follow_entropy_thresholds() {
+        assert_eq!(
+            PasswordStrength::from_entropy_bits(10.0),
+            PasswordStrength::VeryWeak
+        );
+        assert_eq!(
+            PasswordStrength::from_entropy_bits(30.0),
+            PasswordStrength::Weak
+        );
+        assert_eq!(
+            PasswordStrength::from_entropy_bits(45.0),
+            PasswordStrength::Reasonable
+        );
+        assert_eq!(
+            PasswordStrength::from_entropy_bits(90.0),
+            PasswordStrength::Strong
+        );
+        assert_eq!(
+            PasswordStrength::from_entropy_bits(200.0),
+            PasswordStrength::VeryStrong
+        );
+    }
+}